@@ -1,19 +1,13 @@
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use serde_json::{json, Value};
-use std::io::{Read, Write};
-use std::net::{TcpStream, ToSocketAddrs};
-use std::time::Duration;
-
-#[cfg(target_os = "windows")]
 use std::env;
-#[cfg(target_os = "windows")]
 use std::fs;
-#[cfg(target_os = "windows")]
+use std::io::{Read, Write};
+use std::net::{TcpStream, ToSocketAddrs};
 use std::path::{Path, PathBuf};
-#[cfg(target_os = "windows")]
 use std::process::Command;
-#[cfg(target_os = "windows")]
 use std::thread;
+use std::time::Duration;
 
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "camelCase")]
@@ -62,6 +56,36 @@ struct BlenderAutoSetupResult {
   details: Vec<String>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BlenderConnectionSettings {
+  host: String,
+  port: u16,
+  connect_timeout_secs: u64,
+  read_timeout_secs: u64,
+  write_timeout_secs: u64,
+}
+
+impl Default for BlenderConnectionSettings {
+  fn default() -> Self {
+    Self {
+      host: "127.0.0.1".to_string(),
+      port: 9876,
+      connect_timeout_secs: 5,
+      read_timeout_secs: 20,
+      write_timeout_secs: 10,
+    }
+  }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DesktopIntegrationResult {
+  ok: bool,
+  message: String,
+  details: Vec<String>,
+}
+
 #[tauri::command]
 fn healthcheck() -> &'static str {
   "ok"
@@ -82,44 +106,217 @@ fn setup_blender_one_click() -> Result<BlenderAutoSetupResult, String> {
   setup_blender_one_click_impl()
 }
 
+#[tauri::command]
+fn register_desktop_integration(
+  all_users: Option<bool>,
+) -> Result<DesktopIntegrationResult, String> {
+  register_desktop_integration_impl(all_users.unwrap_or(false))
+}
+
+#[tauri::command]
+fn unregister_desktop_integration(
+  all_users: Option<bool>,
+) -> Result<DesktopIntegrationResult, String> {
+  unregister_desktop_integration_impl(all_users.unwrap_or(false))
+}
+
+#[tauri::command]
+fn get_blender_settings() -> BlenderConnectionSettings {
+  load_blender_settings_impl().unwrap_or_default()
+}
+
+#[tauri::command]
+fn set_blender_settings(
+  settings: BlenderConnectionSettings,
+) -> Result<BlenderConnectionSettings, String> {
+  save_blender_settings_impl(&settings)?;
+  Ok(settings)
+}
+
+#[tauri::command]
+fn launch_blender_with_socket(
+  host: Option<String>,
+  port: Option<u16>,
+) -> Result<String, String> {
+  launch_blender_with_socket_impl(&resolved_connection_settings(host, port), None)
+}
+
 #[tauri::command]
 fn check_blender_socket(host: Option<String>, port: Option<u16>) -> BlenderSocketStatus {
-  let resolved_host = host.unwrap_or_else(|| "127.0.0.1".to_string());
-  let resolved_port = port.unwrap_or(9876);
+  check_blender_socket_impl(resolved_connection_settings(host, port))
+}
+
+#[tauri::command]
+fn execute_blender_code(
+  code: String,
+  host: Option<String>,
+  port: Option<u16>,
+) -> Result<BlenderCommandResult, String> {
+  execute_blender_code_impl(code, resolved_connection_settings(host, port))
+}
+
+/// Loads the persisted connection settings and applies any per-call host
+/// or port override on top, without persisting the override.
+fn resolved_connection_settings(
+  host: Option<String>,
+  port: Option<u16>,
+) -> BlenderConnectionSettings {
+  let mut settings = load_blender_settings_impl().unwrap_or_default();
+  if let Some(host) = host {
+    settings.host = host;
+  }
+  if let Some(port) = port {
+    settings.port = port;
+  }
+  settings
+}
+
+fn load_blender_settings_impl() -> Result<BlenderConnectionSettings, String> {
+  let path = blender_settings_path()?;
+  if !path.is_file() {
+    return Ok(BlenderConnectionSettings::default());
+  }
+
+  let contents = fs::read_to_string(&path)
+    .map_err(|err| format!("Failed reading {}: {err}", path.display()))?;
+  serde_json::from_str(&contents)
+    .map_err(|err| format!("Failed parsing {}: {err}", path.display()))
+}
+
+fn save_blender_settings_impl(settings: &BlenderConnectionSettings) -> Result<(), String> {
+  let path = blender_settings_path()?;
+  if let Some(parent) = path.parent() {
+    fs::create_dir_all(parent)
+      .map_err(|err| format!("Failed creating {}: {err}", parent.display()))?;
+  }
+
+  let contents = serde_json::to_string_pretty(settings)
+    .map_err(|err| format!("Failed serializing Blender connection settings: {err}"))?;
+  fs::write(&path, contents).map_err(|err| format!("Failed writing {}: {err}", path.display()))
+}
+
+#[cfg(target_os = "windows")]
+fn blender_settings_path() -> Result<PathBuf, String> {
+  let app_data = env::var("APPDATA").map_err(|_| "APPDATA is not available.".to_string())?;
+  Ok(PathBuf::from(app_data).join("Blynd").join("settings.json"))
+}
+
+#[cfg(target_os = "macos")]
+fn blender_settings_path() -> Result<PathBuf, String> {
+  let home = env::var("HOME").map_err(|_| "HOME is not available.".to_string())?;
+  Ok(PathBuf::from(home).join("Library/Application Support/Blynd/settings.json"))
+}
+
+#[cfg(target_os = "linux")]
+fn blender_settings_path() -> Result<PathBuf, String> {
+  let home = env::var("HOME").map_err(|_| "HOME is not available.".to_string())?;
+  let config_home = env::var("XDG_CONFIG_HOME").unwrap_or_else(|_| format!("{home}/.config"));
+  Ok(PathBuf::from(config_home).join("blynd").join("settings.json"))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn blender_settings_path() -> Result<PathBuf, String> {
+  Err("Persisted Blender connection settings are not supported on this OS.".to_string())
+}
+
+/// Launches Blender with the Blynd addon socket enabled. When `blend_file`
+/// is given (e.g. the OS invoked Blynd to open a double-clicked `.blend`
+/// file) it is opened in the launched instance alongside the socket.
+fn launch_blender_with_socket_impl(
+  settings: &BlenderConnectionSettings,
+  blend_file: Option<&Path>,
+) -> Result<String, String> {
+  let scan = detect_blender_installation_impl();
+  let exe_path_str = scan
+    .executable_path
+    .ok_or_else(|| "Blender was not found; cannot launch it with a socket.".to_string())?;
+  let exe_path = PathBuf::from(&exe_path_str);
+
+  let script_path =
+    env::temp_dir().join(format!("blynd_blender_socket_launch_{}.py", settings.port));
+  fs::write(&script_path, blender_socket_bootstrap_script(settings.port))
+    .map_err(|err| format!("Failed writing Blender launch script: {err}"))?;
+
+  let mut command = Command::new(&exe_path);
+  if let Some(blend_file) = blend_file {
+    command.arg(blend_file);
+  }
+  command
+    .arg("--python")
+    .arg(&script_path)
+    // Read by the addon's register()/_configured_port() so the very first,
+    // Blender-driven auto-enable of the addon binds the requested port
+    // instead of racing the explicit start_server(PORT) call below it.
+    .env("BLYND_PORT", settings.port.to_string())
+    .spawn()
+    .map_err(|err| format!("Failed launching Blender: {err}"))?;
+
+  Ok(format!(
+    "Launched Blender from {} with the Blynd addon socket starting on port {}.",
+    exe_path.display(),
+    settings.port
+  ))
+}
+
+/// Builds the `--python` bootstrap script used to enable the Blynd addon and
+/// start its socket server on `port` in a freshly launched, non-backgrounded
+/// Blender process.
+fn blender_socket_bootstrap_script(port: u16) -> String {
+  format!(
+    r#"import sys
+import traceback
+import bpy
+
+MODULE_NAME = "blender_mcp"
+PORT = {port}
+
+try:
+    if MODULE_NAME not in bpy.context.preferences.addons:
+        bpy.ops.preferences.addon_enable(module=MODULE_NAME)
+
+    addon_module = sys.modules.get(MODULE_NAME)
+    if addon_module is not None and hasattr(addon_module, "start_server"):
+        addon_module.start_server(PORT)
+
+    print("BLYND_LAUNCH_OK")
+except Exception as exc:
+    traceback.print_exc()
+    print(f"BLYND_LAUNCH_ERROR: {{exc}}")
+"#
+  )
+}
+
+fn check_blender_socket_impl(settings: BlenderConnectionSettings) -> BlenderSocketStatus {
   let ping_request = json!({
     "type": "get_scene_info",
     "params": {}
   });
+  let BlenderConnectionSettings { host, port, .. } = settings.clone();
 
-  match send_blender_command(&resolved_host, resolved_port, &ping_request) {
+  match send_blender_command(&settings, &ping_request) {
     Ok(_) => BlenderSocketStatus {
       connected: true,
-      host: resolved_host,
-      port: resolved_port,
+      host,
+      port,
       message: "Connected to Blender addon socket.".to_string(),
     },
     Err(err) => BlenderSocketStatus {
       connected: false,
-      host: resolved_host,
-      port: resolved_port,
+      host,
+      port,
       message: format!("Blender socket unavailable: {err}"),
     },
   }
 }
 
-#[tauri::command]
-fn execute_blender_code(
+fn execute_blender_code_impl(
   code: String,
-  host: Option<String>,
-  port: Option<u16>,
+  settings: BlenderConnectionSettings,
 ) -> Result<BlenderCommandResult, String> {
   if code.trim().is_empty() {
     return Err("Generated code is empty.".to_string());
   }
 
-  let resolved_host = host.unwrap_or_else(|| "127.0.0.1".to_string());
-  let resolved_port = port.unwrap_or(9876);
-
   let request = json!({
     "type": "execute_code",
     "params": {
@@ -127,7 +324,7 @@ fn execute_blender_code(
     }
   });
 
-  let response = send_blender_command(&resolved_host, resolved_port, &request)?;
+  let response = send_blender_command(&settings, &request)?;
   let message = response
     .get("message")
     .and_then(Value::as_str)
@@ -142,29 +339,134 @@ fn execute_blender_code(
   })
 }
 
-fn send_blender_command(host: &str, port: u16, payload: &Value) -> Result<Value, String> {
+/// Caps how large a single response frame we'll allocate for. Real payloads
+/// (even full scene dumps) stay well under this; it only exists to stop a
+/// corrupted or non-Blynd peer on the configured host/port from making us
+/// attempt a multi-gigabyte allocation from a bogus length prefix.
+const MAX_BLENDER_FRAME_BYTES: usize = 256 * 1024 * 1024;
+
+/// An error from one attempt to talk to the Blender addon over its socket,
+/// distinguishing a transport-level failure (worth retrying on the older
+/// unframed wire format) from a well-formed response the addon itself
+/// reported as an error (not worth retrying).
+enum BlenderTransportError {
+  Transport(String),
+  Addon(String),
+}
+
+fn send_blender_command(
+  settings: &BlenderConnectionSettings,
+  payload: &Value,
+) -> Result<Value, String> {
+  match send_blender_command_attempt(settings, payload, true) {
+    Ok(value) => Ok(value),
+    Err(BlenderTransportError::Addon(message)) => Err(message),
+    Err(BlenderTransportError::Transport(framed_err)) => {
+      match send_blender_command_attempt(settings, payload, false) {
+        Ok(value) => Ok(value),
+        Err(BlenderTransportError::Addon(message)) => Err(message),
+        // Both attempts failed at the transport level; the framed error is
+        // the more useful one to surface, since the addon is more likely to
+        // be on the current protocol than not.
+        Err(BlenderTransportError::Transport(_)) => Err(framed_err),
+      }
+    }
+  }
+}
+
+/// Makes one attempt to send `payload` and read a response. When `framed` is
+/// true, the request is sent length-prefixed and the response is read with
+/// `read_blender_response`. When false, both sides use the legacy unframed
+/// wire format, for addons that haven't been upgraded yet.
+fn send_blender_command_attempt(
+  settings: &BlenderConnectionSettings,
+  payload: &Value,
+  framed: bool,
+) -> Result<Value, BlenderTransportError> {
+  let host = settings.host.as_str();
+  let port = settings.port;
+
   let mut addresses = (host, port)
     .to_socket_addrs()
-    .map_err(|err| format!("Unable to resolve {host}:{port}: {err}"))?;
-  let address = addresses
-    .next()
-    .ok_or_else(|| format!("No socket address resolved for {host}:{port}"))?;
-
-  let mut stream = TcpStream::connect_timeout(&address, Duration::from_secs(5))
-    .map_err(|err| format!("Could not connect to Blender socket at {host}:{port}: {err}"))?;
+    .map_err(|err| BlenderTransportError::Transport(format!("Unable to resolve {host}:{port}: {err}")))?;
+  let address = addresses.next().ok_or_else(|| {
+    BlenderTransportError::Transport(format!("No socket address resolved for {host}:{port}"))
+  })?;
+
+  let mut stream =
+    TcpStream::connect_timeout(&address, Duration::from_secs(settings.connect_timeout_secs))
+      .map_err(|err| {
+        BlenderTransportError::Transport(format!(
+          "Could not connect to Blender socket at {host}:{port}: {err}"
+        ))
+      })?;
 
   stream
-    .set_write_timeout(Some(Duration::from_secs(10)))
-    .map_err(|err| format!("Failed to set write timeout: {err}"))?;
+    .set_write_timeout(Some(Duration::from_secs(settings.write_timeout_secs)))
+    .map_err(|err| BlenderTransportError::Transport(format!("Failed to set write timeout: {err}")))?;
   stream
-    .set_read_timeout(Some(Duration::from_secs(20)))
-    .map_err(|err| format!("Failed to set read timeout: {err}"))?;
+    .set_read_timeout(Some(Duration::from_secs(settings.read_timeout_secs)))
+    .map_err(|err| BlenderTransportError::Transport(format!("Failed to set read timeout: {err}")))?;
 
   let request_json = payload.to_string();
+  let request_bytes = request_json.as_bytes();
+
+  let write_result = if framed {
+    let mut framed_request = Vec::with_capacity(4 + request_bytes.len());
+    framed_request.extend_from_slice(&(request_bytes.len() as u32).to_be_bytes());
+    framed_request.extend_from_slice(request_bytes);
+    stream.write_all(&framed_request)
+  } else {
+    stream.write_all(request_bytes)
+  };
+  write_result.map_err(|err| {
+    BlenderTransportError::Transport(format!("Failed sending command to Blender socket: {err}"))
+  })?;
+
+  let response_bytes = if framed {
+    read_blender_response(&mut stream)
+  } else {
+    read_legacy_unframed_response(&mut stream)
+  }
+  .map_err(BlenderTransportError::Transport)?;
+
+  let parsed = serde_json::from_slice::<Value>(&response_bytes).map_err(|err| {
+    BlenderTransportError::Transport(format!("Blender response was not valid JSON: {err}"))
+  })?;
+
+  validate_blender_response(parsed).map_err(BlenderTransportError::Addon)
+}
+
+/// Reads one response off `stream`. The wire protocol is length prefixed: a
+/// 4-byte big-endian frame length followed by exactly that many bytes of
+/// JSON, read into a preallocated buffer and parsed once.
+fn read_blender_response(stream: &mut TcpStream) -> Result<Vec<u8>, String> {
+  let mut length_prefix = [0_u8; 4];
+  stream.read_exact(&mut length_prefix).map_err(|err| {
+    if err.kind() == std::io::ErrorKind::UnexpectedEof {
+      "No response received from Blender addon. Make sure addon server is running.".to_string()
+    } else {
+      format!("Failed reading Blender response frame length: {err}")
+    }
+  })?;
+  let frame_len = u32::from_be_bytes(length_prefix) as usize;
+
+  if frame_len > MAX_BLENDER_FRAME_BYTES {
+    return Err(format!(
+      "Blender response frame of {frame_len} bytes exceeds the {MAX_BLENDER_FRAME_BYTES}-byte limit; \
+       the addon may be on an older, unframed protocol."
+    ));
+  }
+
+  let mut body = vec![0_u8; frame_len];
   stream
-    .write_all(request_json.as_bytes())
-    .map_err(|err| format!("Failed sending command to Blender socket: {err}"))?;
+    .read_exact(&mut body)
+    .map_err(|err| format!("Failed reading {frame_len}-byte Blender response frame: {err}"))?;
+
+  Ok(body)
+}
 
+fn read_legacy_unframed_response(stream: &mut TcpStream) -> Result<Vec<u8>, String> {
   let mut all_bytes: Vec<u8> = Vec::new();
   let mut buffer = [0_u8; 8192];
 
@@ -174,8 +476,8 @@ fn send_blender_command(host: &str, port: u16, payload: &Value) -> Result<Value,
       Ok(read_len) => {
         all_bytes.extend_from_slice(&buffer[..read_len]);
 
-        if let Ok(parsed) = serde_json::from_slice::<Value>(&all_bytes) {
-          return validate_blender_response(parsed);
+        if serde_json::from_slice::<Value>(&all_bytes).is_ok() {
+          break;
         }
       }
       Err(err)
@@ -191,12 +493,12 @@ fn send_blender_command(host: &str, port: u16, payload: &Value) -> Result<Value,
   }
 
   if all_bytes.is_empty() {
-    return Err("No response received from Blender addon. Make sure addon server is running.".to_string());
+    return Err(
+      "No response received from Blender addon. Make sure addon server is running.".to_string(),
+    );
   }
 
-  let parsed = serde_json::from_slice::<Value>(&all_bytes)
-    .map_err(|err| format!("Blender response was not valid JSON: {err}"))?;
-  validate_blender_response(parsed)
+  Ok(all_bytes)
 }
 
 fn validate_blender_response(response: Value) -> Result<Value, String> {
@@ -215,8 +517,10 @@ fn validate_blender_response(response: Value) -> Result<Value, String> {
   Ok(response)
 }
 
-#[cfg(target_os = "windows")]
-fn check_blender_socket_with_retry(host: &str, port: u16, attempts: usize) -> BlenderSocketStatus {
+fn check_blender_socket_with_retry(
+  settings: &BlenderConnectionSettings,
+  attempts: usize,
+) -> BlenderSocketStatus {
   let total_attempts = attempts.max(1);
 
   for attempt in 0..total_attempts {
@@ -225,12 +529,12 @@ fn check_blender_socket_with_retry(host: &str, port: u16, attempts: usize) -> Bl
       "params": {}
     });
 
-    match send_blender_command(host, port, &ping_request) {
+    match send_blender_command(settings, &ping_request) {
       Ok(_) => {
         return BlenderSocketStatus {
           connected: true,
-          host: host.to_string(),
-          port,
+          host: settings.host.clone(),
+          port: settings.port,
           message: "Connected to Blender addon socket.".to_string(),
         };
       }
@@ -238,8 +542,8 @@ fn check_blender_socket_with_retry(host: &str, port: u16, attempts: usize) -> Bl
         if attempt + 1 == total_attempts {
           return BlenderSocketStatus {
             connected: false,
-            host: host.to_string(),
-            port,
+            host: settings.host.clone(),
+            port: settings.port,
             message: format!("Blender socket unavailable: {err}"),
           };
         }
@@ -251,8 +555,8 @@ fn check_blender_socket_with_retry(host: &str, port: u16, attempts: usize) -> Bl
 
   BlenderSocketStatus {
     connected: false,
-    host: host.to_string(),
-    port,
+    host: settings.host.clone(),
+    port: settings.port,
     message: "Blender socket check failed unexpectedly.".to_string(),
   }
 }
@@ -308,17 +612,112 @@ fn detect_blender_installation_impl() -> BlenderInstallScan {
   }
 }
 
-#[cfg(not(target_os = "windows"))]
+#[cfg(target_os = "macos")]
+fn detect_blender_installation_impl() -> BlenderInstallScan {
+  let mut searched_paths: Vec<String> = Vec::new();
+  let mut candidates: Vec<PathBuf> = vec![PathBuf::from(
+    "/Applications/Blender.app/Contents/MacOS/Blender",
+  )];
+
+  if let Ok(home) = env::var("HOME") {
+    candidates.push(
+      PathBuf::from(home).join("Applications/Blender.app/Contents/MacOS/Blender"),
+    );
+  }
+
+  for candidate in candidates {
+    searched_paths.push(candidate.display().to_string());
+    if candidate.is_file() {
+      return BlenderInstallScan {
+        found: true,
+        executable_path: Some(candidate.display().to_string()),
+        searched_paths,
+        message: "Blender installation detected.".to_string(),
+      };
+    }
+  }
+
+  BlenderInstallScan {
+    found: false,
+    executable_path: None,
+    searched_paths,
+    message: "Blender was not found in common macOS installation paths.".to_string(),
+  }
+}
+
+#[cfg(target_os = "linux")]
+fn detect_blender_installation_impl() -> BlenderInstallScan {
+  let mut searched_paths: Vec<String> = Vec::new();
+  let mut seen_paths = std::collections::HashSet::new();
+  let mut candidates: Vec<PathBuf> = vec![
+    PathBuf::from("/usr/bin/blender"),
+    PathBuf::from("/usr/local/bin/blender"),
+    PathBuf::from("/var/lib/flatpak/exports/bin/org.blender.Blender"),
+    PathBuf::from("/snap/bin/blender"),
+  ];
+
+  if let Ok(home) = env::var("HOME") {
+    candidates.push(PathBuf::from(home).join(".local/share/flatpak/exports/bin/org.blender.Blender"));
+  }
+
+  if let Ok(opt_entries) = fs::read_dir("/opt") {
+    let mut opt_matches: Vec<PathBuf> = opt_entries
+      .flatten()
+      .map(|entry| entry.path())
+      .filter(|path| {
+        path.is_dir()
+          && path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("blender"))
+      })
+      .map(|dir| dir.join("blender"))
+      .collect();
+    opt_matches.sort();
+    candidates.append(&mut opt_matches);
+  }
+
+  if let Some(path_var) = env::var_os("PATH") {
+    for dir in env::split_paths(&path_var) {
+      candidates.push(dir.join("blender"));
+    }
+  }
+
+  for candidate in candidates {
+    let key = candidate.display().to_string();
+    if !seen_paths.insert(key.clone()) {
+      continue;
+    }
+    searched_paths.push(key);
+
+    if candidate.is_file() {
+      return BlenderInstallScan {
+        found: true,
+        executable_path: Some(candidate.display().to_string()),
+        searched_paths,
+        message: "Blender installation detected.".to_string(),
+      };
+    }
+  }
+
+  BlenderInstallScan {
+    found: false,
+    executable_path: None,
+    searched_paths,
+    message: "Blender was not found in common Linux installation paths.".to_string(),
+  }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
 fn detect_blender_installation_impl() -> BlenderInstallScan {
   BlenderInstallScan {
     found: false,
     executable_path: None,
     searched_paths: Vec::new(),
-    message: "Windows Blender scan is disabled on this OS.".to_string(),
+    message: "Blender scan is disabled on this OS.".to_string(),
   }
 }
 
-#[cfg(target_os = "windows")]
 fn install_blender_addon_impl() -> Result<AddonInstallResult, String> {
   const ADDON_SOURCE: &str = include_str!("../resources/blender_mcp_addon.py");
 
@@ -340,14 +739,9 @@ fn install_blender_addon_impl() -> Result<AddonInstallResult, String> {
   })
 }
 
-#[cfg(not(target_os = "windows"))]
-fn install_blender_addon_impl() -> Result<AddonInstallResult, String> {
-  Err("Automatic addon installation is currently implemented for Windows builds only.".to_string())
-}
-
-#[cfg(target_os = "windows")]
 fn setup_blender_one_click_impl() -> Result<BlenderAutoSetupResult, String> {
   let mut details = Vec::new();
+  let settings = load_blender_settings_impl().unwrap_or_default();
   let scan = detect_blender_installation_impl();
   details.push(scan.message.clone());
 
@@ -359,8 +753,8 @@ fn setup_blender_one_click_impl() -> Result<BlenderAutoSetupResult, String> {
       blender_version: None,
       socket_status: BlenderSocketStatus {
         connected: false,
-        host: "127.0.0.1".to_string(),
-        port: 9876,
+        host: settings.host,
+        port: settings.port,
         message: "Blender socket was not checked because Blender was not detected.".to_string(),
       },
       message: "Blender was not found. Install Blender first.".to_string(),
@@ -380,12 +774,25 @@ fn setup_blender_one_click_impl() -> Result<BlenderAutoSetupResult, String> {
   let enable_output = enable_addon_in_blender_preferences(&exe_path)?;
   details.push(enable_output);
 
-  let socket_status = check_blender_socket_with_retry("127.0.0.1", 9876, 3);
+  let launched = match launch_blender_with_socket_impl(&settings, None) {
+    Ok(launch_message) => {
+      details.push(launch_message);
+      true
+    }
+    Err(err) => {
+      details.push(format!("Could not auto-launch Blender: {err}"));
+      false
+    }
+  };
+
+  let socket_status = check_blender_socket_with_retry(&settings, 5);
   let ok = socket_status.connected;
   let message = if ok {
     "Blender one-click setup completed. Addon is installed, enabled, and socket is live.".to_string()
+  } else if launched {
+    "One-click setup completed (addon installed + enabled) and Blender was launched; waiting for its socket server to come up.".to_string()
   } else {
-    "One-click setup completed (addon installed + enabled). Open or restart Blender once; the addon will auto-start the socket server.".to_string()
+    "One-click setup completed (addon installed + enabled), but Blender could not be launched automatically.".to_string()
   };
 
   Ok(BlenderAutoSetupResult {
@@ -399,12 +806,257 @@ fn setup_blender_one_click_impl() -> Result<BlenderAutoSetupResult, String> {
   })
 }
 
-#[cfg(not(target_os = "windows"))]
-fn setup_blender_one_click_impl() -> Result<BlenderAutoSetupResult, String> {
-  Err("One-click Blender setup is currently implemented for Windows builds only.".to_string())
+const DESKTOP_FILE_NAME: &str = "blynd.desktop";
+const BLEND_MIME_TYPE: &str = "application/x-blender";
+const BLYND_URI_SCHEME: &str = "x-scheme-handler/blynd";
+
+#[cfg(target_os = "linux")]
+fn register_desktop_integration_impl(all_users: bool) -> Result<DesktopIntegrationResult, String> {
+  let mut details = Vec::new();
+
+  if all_users && !is_running_as_root() {
+    return Err(
+      "Registering Blynd for all users requires running as root (e.g. with sudo).".to_string(),
+    );
+  }
+
+  let data_dir = linux_desktop_data_dir(all_users)?;
+  let applications_dir = data_dir.join("applications");
+  let icons_dir = data_dir.join("icons/hicolor/256x256/apps");
+
+  fs::create_dir_all(&applications_dir)
+    .map_err(|err| format!("Failed creating {}: {err}", applications_dir.display()))?;
+
+  let exe_path = env::current_exe()
+    .map_err(|err| format!("Failed resolving the Blynd executable path: {err}"))?;
+
+  let desktop_entry = format!(
+    "[Desktop Entry]\n\
+Type=Application\n\
+Name=Blynd\n\
+Comment=AI-assisted Blender automation\n\
+Exec=\"{}\" %u\n\
+Icon=blynd\n\
+Terminal=false\n\
+Categories=Graphics;3DGraphics;\n\
+MimeType={BLEND_MIME_TYPE};{BLYND_URI_SCHEME};\n",
+    exe_path.display()
+  );
+
+  let desktop_file_path = applications_dir.join(DESKTOP_FILE_NAME);
+  fs::write(&desktop_file_path, desktop_entry)
+    .map_err(|err| format!("Failed writing {}: {err}", desktop_file_path.display()))?;
+  details.push(format!("Wrote desktop entry to {}", desktop_file_path.display()));
+
+  match locate_bundled_icon(&exe_path) {
+    Some(icon_source) => {
+      fs::create_dir_all(&icons_dir)
+        .map_err(|err| format!("Failed creating {}: {err}", icons_dir.display()))?;
+      let icon_dest = icons_dir.join("blynd.png");
+      fs::copy(&icon_source, &icon_dest)
+        .map_err(|err| format!("Failed copying icon to {}: {err}", icon_dest.display()))?;
+      details.push(format!("Installed icon to {}", icon_dest.display()));
+    }
+    None => details.push("No bundled icon found next to the executable; skipped icon install.".to_string()),
+  }
+
+  details.push(run_desktop_integration_command(
+    Command::new("update-desktop-database").arg(&applications_dir),
+  ));
+  details.push(run_desktop_integration_command(
+    Command::new("xdg-mime").args(["default", DESKTOP_FILE_NAME, BLEND_MIME_TYPE]),
+  ));
+  details.push(run_desktop_integration_command(
+    Command::new("xdg-mime").args(["default", DESKTOP_FILE_NAME, BLYND_URI_SCHEME]),
+  ));
+
+  Ok(DesktopIntegrationResult {
+    ok: true,
+    message: "Blynd is now registered as a handler for .blend files and blynd:// links."
+      .to_string(),
+    details,
+  })
+}
+
+#[cfg(target_os = "linux")]
+fn unregister_desktop_integration_impl(
+  all_users: bool,
+) -> Result<DesktopIntegrationResult, String> {
+  let mut details = Vec::new();
+
+  if all_users && !is_running_as_root() {
+    return Err(
+      "Unregistering Blynd for all users requires running as root (e.g. with sudo).".to_string(),
+    );
+  }
+
+  let data_dir = linux_desktop_data_dir(all_users)?;
+  let desktop_file_path = data_dir.join("applications").join(DESKTOP_FILE_NAME);
+  let icon_path = data_dir.join("icons/hicolor/256x256/apps/blynd.png");
+
+  if desktop_file_path.exists() {
+    fs::remove_file(&desktop_file_path)
+      .map_err(|err| format!("Failed removing {}: {err}", desktop_file_path.display()))?;
+    details.push(format!("Removed desktop entry {}", desktop_file_path.display()));
+  } else {
+    details.push(format!("No desktop entry found at {}", desktop_file_path.display()));
+  }
+
+  if icon_path.exists() {
+    fs::remove_file(&icon_path)
+      .map_err(|err| format!("Failed removing {}: {err}", icon_path.display()))?;
+    details.push(format!("Removed icon {}", icon_path.display()));
+  }
+
+  details.push(run_desktop_integration_command(
+    Command::new("update-desktop-database").arg(data_dir.join("applications")),
+  ));
+
+  Ok(DesktopIntegrationResult {
+    ok: true,
+    message: "Blynd desktop integration has been removed.".to_string(),
+    details,
+  })
+}
+
+#[cfg(target_os = "linux")]
+fn linux_desktop_data_dir(all_users: bool) -> Result<PathBuf, String> {
+  if all_users {
+    return Ok(PathBuf::from("/usr/local/share"));
+  }
+
+  if let Ok(xdg_data_home) = env::var("XDG_DATA_HOME") {
+    return Ok(PathBuf::from(xdg_data_home));
+  }
+
+  let home = env::var("HOME").map_err(|_| "HOME is not available.".to_string())?;
+  Ok(PathBuf::from(home).join(".local/share"))
+}
+
+#[cfg(target_os = "linux")]
+fn is_running_as_root() -> bool {
+  fs::read_to_string("/proc/self/status")
+    .ok()
+    .and_then(|status| {
+      status.lines().find_map(|line| {
+        line
+          .strip_prefix("Uid:")
+          .and_then(|rest| rest.split_whitespace().next())
+          .and_then(|uid| uid.parse::<u32>().ok())
+      })
+    })
+    .is_some_and(|real_uid| real_uid == 0)
+}
+
+#[cfg(target_os = "linux")]
+fn locate_bundled_icon(exe_path: &Path) -> Option<PathBuf> {
+  let exe_dir = exe_path.parent()?;
+  ["icon.png", "resources/icon.png", "../share/icons/hicolor/256x256/apps/blynd.png"]
+    .into_iter()
+    .map(|candidate| exe_dir.join(candidate))
+    .find(|path| path.is_file())
+}
+
+#[cfg(target_os = "linux")]
+fn run_desktop_integration_command(command: &mut Command) -> String {
+  let program = command.get_program().to_string_lossy().to_string();
+  match command.output() {
+    Ok(output) if output.status.success() => format!("{program}: ok"),
+    Ok(output) => format!(
+      "{program}: exited with {:?} ({})",
+      output.status.code(),
+      String::from_utf8_lossy(&output.stderr).trim()
+    ),
+    Err(err) => format!("{program}: failed to run ({err})"),
+  }
+}
+
+#[cfg(target_os = "windows")]
+fn register_desktop_integration_impl(_all_users: bool) -> Result<DesktopIntegrationResult, String> {
+  let exe_path = env::current_exe()
+    .map_err(|err| format!("Failed resolving the Blynd executable path: {err}"))?;
+  let open_command = format!("\"{}\" \"%1\"", exe_path.display());
+
+  let mut details = Vec::new();
+  details.push(run_reg_command(&[
+    "add", r"HKCU\Software\Classes\.blend", "/ve", "/d", "Blynd.BlendFile", "/f",
+  ]));
+  details.push(run_reg_command(&[
+    "add",
+    r"HKCU\Software\Classes\Blynd.BlendFile\shell\open\command",
+    "/ve",
+    "/d",
+    &open_command,
+    "/f",
+  ]));
+  details.push(run_reg_command(&[
+    "add", r"HKCU\Software\Classes\blynd", "/ve", "/d", "URL:Blynd Protocol", "/f",
+  ]));
+  details.push(run_reg_command(&[
+    "add", r"HKCU\Software\Classes\blynd", "/v", "URL Protocol", "/d", "", "/f",
+  ]));
+  details.push(run_reg_command(&[
+    "add",
+    r"HKCU\Software\Classes\blynd\shell\open\command",
+    "/ve",
+    "/d",
+    &open_command,
+    "/f",
+  ]));
+
+  Ok(DesktopIntegrationResult {
+    ok: true,
+    message: "Blynd is now registered as a handler for .blend files and blynd:// links."
+      .to_string(),
+    details,
+  })
 }
 
 #[cfg(target_os = "windows")]
+fn unregister_desktop_integration_impl(
+  _all_users: bool,
+) -> Result<DesktopIntegrationResult, String> {
+  let details = vec![
+    run_reg_command(&["delete", r"HKCU\Software\Classes\.blend", "/f"]),
+    run_reg_command(&["delete", r"HKCU\Software\Classes\Blynd.BlendFile", "/f"]),
+    run_reg_command(&["delete", r"HKCU\Software\Classes\blynd", "/f"]),
+  ];
+
+  Ok(DesktopIntegrationResult {
+    ok: true,
+    message: "Blynd desktop integration has been removed.".to_string(),
+    details,
+  })
+}
+
+#[cfg(target_os = "windows")]
+fn run_reg_command(args: &[&str]) -> String {
+  match Command::new("reg").args(args).output() {
+    Ok(output) if output.status.success() => format!("reg {}: ok", args.join(" ")),
+    Ok(output) => format!(
+      "reg {}: exited with {:?} ({})",
+      args.join(" "),
+      output.status.code(),
+      String::from_utf8_lossy(&output.stderr).trim()
+    ),
+    Err(err) => format!("reg {}: failed to run ({err})", args.join(" ")),
+  }
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn register_desktop_integration_impl(
+  _all_users: bool,
+) -> Result<DesktopIntegrationResult, String> {
+  Err("Desktop integration is not supported on this OS.".to_string())
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "linux")))]
+fn unregister_desktop_integration_impl(
+  _all_users: bool,
+) -> Result<DesktopIntegrationResult, String> {
+  Err("Desktop integration is not supported on this OS.".to_string())
+}
+
 fn enable_addon_in_blender_preferences(blender_exe: &Path) -> Result<String, String> {
   if !blender_exe.is_file() {
     return Err(format!(
@@ -471,9 +1123,8 @@ except Exception as exc:
   ))
 }
 
-#[cfg(target_os = "windows")]
 fn truncate_log(input: &str, max_chars: usize) -> String {
-  let normalized = input.replace('\r', " ").replace('\n', " ").trim().to_string();
+  let normalized = input.replace(['\r', '\n'], " ").trim().to_string();
   if normalized.chars().count() <= max_chars {
     return normalized;
   }
@@ -482,6 +1133,31 @@ fn truncate_log(input: &str, max_chars: usize) -> String {
   format!("{truncated}...")
 }
 
+/// Finds the highest Blender version folder directly under `blender_root` and
+/// returns its name, which is the version string, e.g. `3.6` or `4.1`.
+fn find_latest_blender_version_dir(blender_root: &Path) -> Option<String> {
+  let entries = fs::read_dir(blender_root).ok()?;
+  let mut versions: Vec<(String, (u32, u32, u32))> = Vec::new();
+
+  for entry in entries.flatten() {
+    let path = entry.path();
+    if !path.is_dir() {
+      continue;
+    }
+
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+      continue;
+    };
+
+    if let Some(parsed) = parse_blender_version(name) {
+      versions.push((name.to_string(), parsed));
+    }
+  }
+
+  versions.sort_by_key(|v| std::cmp::Reverse(v.1));
+  versions.into_iter().next().map(|(name, _)| name)
+}
+
 #[cfg(target_os = "windows")]
 fn find_latest_blender_addons_dir() -> Result<(String, PathBuf), String> {
   let app_data = env::var("APPDATA").map_err(|_| "APPDATA is not available.".to_string())?;
@@ -496,28 +1172,34 @@ fn find_latest_blender_addons_dir() -> Result<(String, PathBuf), String> {
     ));
   }
 
-  let mut versions: Vec<(String, (u32, u32, u32))> = Vec::new();
+  let Some(latest_version) = find_latest_blender_version_dir(&blender_root) else {
+    return Err(format!(
+      "No Blender version folders found in {}",
+      blender_root.display()
+    ));
+  };
 
-  let entries = fs::read_dir(&blender_root)
-    .map_err(|err| format!("Failed listing {}: {err}", blender_root.display()))?;
+  let addons_dir = blender_root
+    .join(&latest_version)
+    .join("scripts")
+    .join("addons");
 
-  for entry in entries.flatten() {
-    let path = entry.path();
-    if !path.is_dir() {
-      continue;
-    }
+  Ok((latest_version, addons_dir))
+}
 
-    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
-      continue;
-    };
+#[cfg(target_os = "macos")]
+fn find_latest_blender_addons_dir() -> Result<(String, PathBuf), String> {
+  let home = env::var("HOME").map_err(|_| "HOME is not available.".to_string())?;
+  let blender_root = PathBuf::from(home).join("Library/Application Support/Blender");
 
-    if let Some(parsed) = parse_blender_version(name) {
-      versions.push((name.to_string(), parsed));
-    }
+  if !blender_root.exists() {
+    return Err(format!(
+      "Blender user config directory not found at {}",
+      blender_root.display()
+    ));
   }
 
-  versions.sort_by(|a, b| b.1.cmp(&a.1));
-  let Some((latest_version, _)) = versions.first() else {
+  let Some(latest_version) = find_latest_blender_version_dir(&blender_root) else {
     return Err(format!(
       "No Blender version folders found in {}",
       blender_root.display()
@@ -525,14 +1207,55 @@ fn find_latest_blender_addons_dir() -> Result<(String, PathBuf), String> {
   };
 
   let addons_dir = blender_root
-    .join(latest_version)
+    .join(&latest_version)
     .join("scripts")
     .join("addons");
 
-  Ok((latest_version.clone(), addons_dir))
+  Ok((latest_version, addons_dir))
+}
+
+#[cfg(target_os = "linux")]
+fn find_latest_blender_addons_dir() -> Result<(String, PathBuf), String> {
+  let home = env::var("HOME").map_err(|_| "HOME is not available.".to_string())?;
+  let xdg_config_home =
+    env::var("XDG_CONFIG_HOME").unwrap_or_else(|_| format!("{home}/.config"));
+
+  let candidate_roots = [
+    PathBuf::from(xdg_config_home).join("blender"),
+    PathBuf::from(&home).join(".var/app/org.blender.Blender/config/blender"),
+    PathBuf::from(&home).join("snap/blender/current/.config/blender"),
+    PathBuf::from(&home).join("snap/blender/common/.config/blender"),
+  ];
+
+  for blender_root in &candidate_roots {
+    if !blender_root.exists() {
+      continue;
+    }
+
+    if let Some(latest_version) = find_latest_blender_version_dir(blender_root) {
+      let addons_dir = blender_root
+        .join(&latest_version)
+        .join("scripts")
+        .join("addons");
+      return Ok((latest_version, addons_dir));
+    }
+  }
+
+  Err(format!(
+    "No Blender config directory found in any of: {}",
+    candidate_roots
+      .iter()
+      .map(|root| root.display().to_string())
+      .collect::<Vec<_>>()
+      .join(", ")
+  ))
+}
+
+#[cfg(not(any(target_os = "windows", target_os = "macos", target_os = "linux")))]
+fn find_latest_blender_addons_dir() -> Result<(String, PathBuf), String> {
+  Err("Blender addon installation is disabled on this OS.".to_string())
 }
 
-#[cfg(target_os = "windows")]
 fn parse_blender_version(input: &str) -> Option<(u32, u32, u32)> {
   let mut parts = input.split('.');
   let major = parts.next()?.parse::<u32>().ok()?;
@@ -589,14 +1312,174 @@ fn find_blender_executable(base_path: &Path) -> Option<PathBuf> {
   None
 }
 
+const CLI_SUBCOMMANDS: &[&str] = &["detect", "install-addon", "setup", "socket", "exec"];
+
+/// Parses `std::env::args()` for a recognized headless subcommand and, if
+/// one is present, runs it and returns the process exit code. Returns
+/// `None` when no recognized subcommand was given, so the caller can fall
+/// through to the normal GUI launch.
+fn run_cli() -> Option<i32> {
+  let args: Vec<String> = env::args().skip(1).collect();
+  let command = args.first()?;
+
+  match command.as_str() {
+    "--register" => {
+      let all_users = args[1..].iter().any(|arg| arg == "--all-users");
+      return Some(print_cli_result(register_desktop_integration_impl(
+        all_users,
+      )));
+    }
+    "--unregister" => {
+      let all_users = args[1..].iter().any(|arg| arg == "--all-users");
+      return Some(print_cli_result(unregister_desktop_integration_impl(
+        all_users,
+      )));
+    }
+    _ => {}
+  }
+
+  if !CLI_SUBCOMMANDS.contains(&command.as_str()) {
+    return None;
+  }
+
+  Some(run_cli_command(command, &args[1..]))
+}
+
+fn run_cli_command(command: &str, rest: &[String]) -> i32 {
+  match command {
+    "detect" => print_cli_ok(&detect_blender_installation_impl()),
+    "install-addon" => print_cli_result(install_blender_addon_impl()),
+    "setup" => print_cli_result(setup_blender_one_click_impl()),
+    "socket" => {
+      let settings = parse_cli_connection_settings(rest);
+      print_cli_ok(&check_blender_socket_impl(settings))
+    }
+    "exec" => print_cli_result(run_cli_exec(rest)),
+    _ => unreachable!("run_cli_command called with an unrecognized subcommand"),
+  }
+}
+
+fn run_cli_exec(rest: &[String]) -> Result<BlenderCommandResult, String> {
+  let file_path = parse_cli_flag(rest, "--file")
+    .ok_or_else(|| "blynd exec requires --file <script.py>".to_string())?;
+  let code = fs::read_to_string(&file_path)
+    .map_err(|err| format!("Failed reading {file_path}: {err}"))?;
+  let settings = parse_cli_connection_settings(rest);
+
+  execute_blender_code_impl(code, settings)
+}
+
+fn parse_cli_flag(args: &[String], flag: &str) -> Option<String> {
+  let mut iter = args.iter();
+  while let Some(arg) = iter.next() {
+    if arg == flag {
+      return iter.next().cloned();
+    }
+  }
+  None
+}
+
+fn parse_cli_connection_settings(args: &[String]) -> BlenderConnectionSettings {
+  let host = parse_cli_flag(args, "--host");
+  let port = parse_cli_flag(args, "--port").and_then(|value| value.parse::<u16>().ok());
+  resolved_connection_settings(host, port)
+}
+
+fn print_cli_ok<T: Serialize>(value: &T) -> i32 {
+  match serde_json::to_string(value) {
+    Ok(json) => {
+      println!("{json}");
+      0
+    }
+    Err(err) => {
+      eprintln!("{}", json!({ "error": format!("Failed serializing result: {err}") }));
+      1
+    }
+  }
+}
+
+fn print_cli_result<T: Serialize>(result: Result<T, String>) -> i32 {
+  match result {
+    Ok(value) => print_cli_ok(&value),
+    Err(err) => {
+      eprintln!("{}", json!({ "error": err }));
+      1
+    }
+  }
+}
+
+/// What to do with a `.blend` path or `blynd://` URI the OS handed us as
+/// argv, via the handler `register_desktop_integration_impl` installs.
+enum LaunchIntent {
+  OneClickSetup,
+  OpenBlendFile(PathBuf),
+}
+
+/// Recognizes a `.blend` path or `blynd://` URI among the launch args. Does
+/// not overlap with `run_cli`'s subcommands, so this only needs to run once
+/// `run_cli` has already declined to handle the first argument.
+fn classify_launch_arg(arg: &str) -> Option<LaunchIntent> {
+  if let Some(action) = arg.strip_prefix("blynd://") {
+    return match action.trim_end_matches('/') {
+      "setup" => Some(LaunchIntent::OneClickSetup),
+      _ => None,
+    };
+  }
+
+  // The desktop entry's Exec line uses %u, so double-clicking a .blend file
+  // hands it to us as a file:// URI rather than a bare path.
+  let path = arg.strip_prefix("file://").unwrap_or(arg);
+
+  if Path::new(path)
+    .extension()
+    .is_some_and(|ext| ext.eq_ignore_ascii_case("blend"))
+  {
+    return Some(LaunchIntent::OpenBlendFile(PathBuf::from(path)));
+  }
+
+  None
+}
+
+/// Runs the flow implied by a launch intent before the GUI window comes up.
+/// Failures are logged rather than propagated since there is no CLI caller
+/// around to report them to.
+fn handle_launch_intent(intent: LaunchIntent) {
+  match intent {
+    LaunchIntent::OneClickSetup => {
+      if let Err(err) = setup_blender_one_click_impl() {
+        eprintln!("One-click setup requested via blynd://setup failed: {err}");
+      }
+    }
+    LaunchIntent::OpenBlendFile(path) => {
+      let settings = load_blender_settings_impl().unwrap_or_default();
+      if let Err(err) = launch_blender_with_socket_impl(&settings, Some(&path)) {
+        eprintln!("Failed to open {} in Blender: {err}", path.display());
+      }
+    }
+  }
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+  if let Some(exit_code) = run_cli() {
+    std::process::exit(exit_code);
+  }
+
+  if let Some(intent) = env::args().nth(1).as_deref().and_then(classify_launch_arg) {
+    handle_launch_intent(intent);
+  }
+
   tauri::Builder::default()
     .invoke_handler(tauri::generate_handler![
       healthcheck,
       detect_blender_installation,
       install_blender_addon,
       setup_blender_one_click,
+      register_desktop_integration,
+      unregister_desktop_integration,
+      get_blender_settings,
+      set_blender_settings,
+      launch_blender_with_socket,
       check_blender_socket,
       execute_blender_code
     ])